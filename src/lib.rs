@@ -13,51 +13,143 @@
 //! stopwatch.stop();
 //! let total_elapsed = stopwatch.elapsed();
 //! ```
+use std::fmt;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
 use std::time::SystemTime;
 
+/// Abstracts over the clock `Stopwatch` reads "now" from (monotonic vs wall-clock).
+pub trait Instant: Copy {
+    /// Returns the current instant according to this clock.
+    fn now() -> Self;
+
+    /// Returns the duration elapsed between `earlier` and `self`, or `None` if `earlier` is
+    /// later than `self` (e.g. the underlying clock moved backward).
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration>;
+
+    /// Returns the duration elapsed between `earlier` and `self`, saturating to
+    /// `Duration::ZERO` if `earlier` is later than `self` instead of panicking.
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.checked_duration_since(earlier)
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+impl Instant for std::time::Instant {
+    fn now() -> Self {
+        std::time::Instant::now()
+    }
+
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+        self.checked_duration_since(earlier)
+    }
+}
+
+impl Instant for SystemTime {
+    fn now() -> Self {
+        SystemTime::now()
+    }
+
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+        self.duration_since(earlier).ok()
+    }
+}
+
+/// A `Stopwatch` backed by the monotonic `std::time::Instant` clock.
+pub type MonotonicStopwatch = Stopwatch<std::time::Instant>;
+
 #[derive(Clone, Copy, Debug)]
-pub struct Stopwatch {
-    start_time: Option<SystemTime>,
+pub struct Stopwatch<I: Instant = std::time::Instant> {
+    start_time: Option<I>,
     elapsed_duration: Duration,
+    name: Option<&'static str>,
 }
 
-impl Stopwatch {
-    /// Creates a Stopwatch.
-    pub fn new() -> Stopwatch {
+impl Stopwatch<std::time::Instant> {
+    /// Creates a Stopwatch. Only implemented for the default, monotonic clock, since Rust
+    /// doesn't apply a default type parameter when inferring a bare call; use
+    /// `Stopwatch::<I>::default()` for a non-default clock.
+    pub fn new() -> Self {
         Stopwatch {
             start_time: None,
             elapsed_duration: Duration::new(0, 0),
+            name: None,
         }
     }
 
-    /// Creates and immediately starts a Stopwatch.
-    pub fn new_started() -> Stopwatch {
-        let mut stopwatch = Stopwatch {
+    /// Creates a Stopwatch carrying `name`, used by its `Display` implementation.
+    pub fn new_named(name: &'static str) -> Self {
+        Stopwatch {
             start_time: None,
             elapsed_duration: Duration::new(0, 0),
-        };
+            name: Some(name),
+        }
+    }
+
+    /// Creates and immediately starts a Stopwatch.
+    pub fn new_started() -> Self {
+        let mut stopwatch = Self::new();
         stopwatch.start();
         stopwatch
     }
 
+    /// Creates a new, already-started Stopwatch wrapped in an `Arc<Mutex<_>>`, together with
+    /// an owned guard that stops it when dropped. The `Arc<Mutex<_>>`-friendly counterpart of
+    /// [`Stopwatch::guard`].
+    pub fn new_guarded() -> (Arc<Mutex<Self>>, OwnedGuard<std::time::Instant>) {
+        let stopwatch = Arc::new(Mutex::new(Stopwatch::new_started()));
+        let guard = OwnedGuard {
+            stopwatch: Arc::clone(&stopwatch),
+        };
+        (stopwatch, guard)
+    }
+}
+
+impl<I: Instant> Stopwatch<I> {
+    /// Returns the name given to this stopwatch via [`Stopwatch::new_named`] or
+    /// [`Stopwatch::set_name`], if any.
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// Sets the name used by this stopwatch's `Display` implementation.
+    pub fn set_name(&mut self, name: &'static str) {
+        self.name = Some(name);
+    }
+
     /// Starts the measurement.
     /// If the stopwatch is already running, then the call has no effect.
     pub fn start(&mut self) {
         if self.start_time.is_none() {
-            self.start_time = Some(SystemTime::now());
+            self.start_time = Some(I::now());
         }
     }
 
     /// Stops the measurement.
     /// The elapsed duration can be obtained using `elapsed()`. If the stopwatch has never been started or has already been stopped, then the call has no effect.
+    ///
+    /// If the underlying clock moved backward since the last `start()`, the interval is
+    /// treated as `Duration::ZERO` instead of panicking.
     pub fn stop(&mut self) {
-        if self.start_time.is_some() {
-            self.elapsed_duration = self.elapsed_duration
-                + (SystemTime::now().duration_since(self.start_time.take().unwrap())).unwrap();
+        if let Some(start_time) = self.start_time.take() {
+            self.elapsed_duration += I::now().saturating_duration_since(start_time);
         }
     }
 
+    /// Pauses the measurement. An alias for `stop()` with clearer call-site semantics when
+    /// the stopwatch is timing a pausable activity rather than a one-shot measurement.
+    pub fn pause(&mut self) {
+        self.stop();
+    }
+
+    /// Resumes the measurement. An alias for `start()`; see `pause()`.
+    pub fn resume(&mut self) {
+        self.start();
+    }
+
     /// Restores the original state of the stopwatch.
     /// If the stopwatch is running, then it will be stopped and the elapsed will be cleared, so it can't be obtained.
     pub fn reset(&mut self) {
@@ -73,9 +165,12 @@ impl Stopwatch {
     }
 
     /// Returns the elapsed time. In case of multiple `start()` and `stop()` the elapsed intervals are accumulated. The elapsed time can be cleared by `reset()` or reset_and_start()`.
+    ///
+    /// If the underlying clock moved backward since the last `start()`, the in-flight
+    /// interval is treated as `Duration::ZERO` instead of panicking.
     pub fn elapsed(&self) -> Duration {
         match self.start_time {
-            Some(t) => self.elapsed_duration + SystemTime::now().duration_since(t).unwrap(),
+            Some(t) => self.elapsed_duration + I::now().saturating_duration_since(t),
             None => self.elapsed_duration,
         }
     }
@@ -84,6 +179,346 @@ impl Stopwatch {
     pub fn is_running(&self) -> bool {
         self.start_time.is_some()
     }
+
+    /// Starts the stopwatch and returns a guard that stops it when dropped, timing the
+    /// enclosing scope without manually pairing `start()`/`stop()`.
+    pub fn guard(&mut self) -> Guard<'_, I> {
+        self.start();
+        Guard { stopwatch: self }
+    }
+
+    /// Starts this stopwatch (already wrapped in an `Arc<Mutex<_>>`) and returns an owned
+    /// guard that stops it when dropped. See [`Stopwatch::guard`] for the borrowed equivalent.
+    pub fn guard_owned(stopwatch: Arc<Mutex<Stopwatch<I>>>) -> OwnedGuard<I> {
+        stopwatch.lock().unwrap().start();
+        OwnedGuard { stopwatch }
+    }
+}
+
+impl<I: Instant> Default for Stopwatch<I> {
+    fn default() -> Self {
+        Stopwatch {
+            start_time: None,
+            elapsed_duration: Duration::new(0, 0),
+            name: None,
+        }
+    }
+}
+
+/// Formats a duration adaptively, picking whichever of ns/us/ms/s is most legible: whole
+/// nanoseconds below 1us, otherwise microseconds/milliseconds/seconds with two decimal places.
+fn fmt_adaptive_duration(elapsed: Duration, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let nanos = elapsed.as_nanos();
+    if nanos < 1_000 {
+        write!(f, "{nanos}ns")
+    } else if nanos < 1_000_000 {
+        write!(f, "{:.2}us", elapsed.as_secs_f64() * 1e6)
+    } else if nanos < 1_000_000_000 {
+        write!(f, "{:.2}ms", elapsed.as_secs_f64() * 1e3)
+    } else {
+        write!(f, "{:.2}s", elapsed.as_secs_f64())
+    }
+}
+
+/// Formats the stopwatch for one-line logging, e.g. `"took 3.42ms"` or `"running"`, prefixed
+/// with the name if one was set via [`Stopwatch::new_named`].
+impl<I: Instant> fmt::Display for Stopwatch<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name {
+            write!(f, "{name} ")?;
+        }
+        if self.is_running() {
+            write!(f, "running")
+        } else {
+            write!(f, "took ")?;
+            fmt_adaptive_duration(self.elapsed(), f)
+        }
+    }
+}
+
+/// An RAII guard that stops a borrowed [`Stopwatch`] when it goes out of scope. Created by
+/// [`Stopwatch::guard`]; derefs to the underlying `Stopwatch`.
+pub struct Guard<'a, I: Instant> {
+    stopwatch: &'a mut Stopwatch<I>,
+}
+
+impl<I: Instant> Deref for Guard<'_, I> {
+    type Target = Stopwatch<I>;
+
+    fn deref(&self) -> &Self::Target {
+        self.stopwatch
+    }
+}
+
+impl<I: Instant> DerefMut for Guard<'_, I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.stopwatch
+    }
+}
+
+impl<I: Instant> Drop for Guard<'_, I> {
+    fn drop(&mut self) {
+        self.stopwatch.stop();
+    }
+}
+
+/// An RAII guard that stops an `Arc<Mutex<Stopwatch>>` when it goes out of scope. Created by
+/// [`Stopwatch::new_guarded`] or [`Stopwatch::guard_owned`]; unlike [`Guard`], it owns its
+/// handle to the stopwatch, so it can be moved into a closure or across threads.
+pub struct OwnedGuard<I: Instant> {
+    stopwatch: Arc<Mutex<Stopwatch<I>>>,
+}
+
+impl<I: Instant> Drop for OwnedGuard<I> {
+    fn drop(&mut self) {
+        if let Ok(mut stopwatch) = self.stopwatch.lock() {
+            stopwatch.stop();
+        }
+    }
+}
+
+/// The result of timing a closure over a number of iterations with [`benchmark`].
+#[derive(Clone, Copy, Debug)]
+pub struct BenchResult {
+    iterations: u32,
+    total: Duration,
+}
+
+impl BenchResult {
+    /// Returns the total time spent running the closure across all iterations.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Returns the number of iterations the closure was run for.
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Returns the average time spent per iteration.
+    ///
+    /// Uses integer `Duration` division to avoid floating point error. If `iterations` is
+    /// zero, returns `Duration::ZERO` rather than dividing by zero.
+    pub fn per_iteration(&self) -> Duration {
+        match self.iterations {
+            0 => Duration::ZERO,
+            iterations => self.total / iterations,
+        }
+    }
+}
+
+/// Times `f` over `iterations` calls and reports the total and per-call duration.
+pub fn benchmark<F: FnMut()>(iterations: u32, mut f: F) -> BenchResult {
+    let mut stopwatch = MonotonicStopwatch::new();
+    stopwatch.reset_and_start();
+    for _ in 0..iterations {
+        f();
+    }
+    stopwatch.stop();
+    BenchResult {
+        iterations,
+        total: stopwatch.elapsed(),
+    }
+}
+
+/// Times each of several named closures over `iterations` calls, so alternatives can be
+/// compared side by side. Returns one `(name, total duration)` pair per closure, in the
+/// same order as `closures`.
+pub fn benchmark_all(
+    iterations: u32,
+    closures: &mut [(&str, &mut dyn FnMut())],
+) -> Vec<(String, Duration)> {
+    closures
+        .iter_mut()
+        .map(|(name, f)| (name.to_string(), benchmark(iterations, f).total()))
+        .collect()
+}
+
+/// The on-the-wire representation of a `Stopwatch` used by its `serde` support; a live
+/// `Instant` can't cross a serialization boundary, so only the elapsed time, running state,
+/// and name are persisted. The name is serialized as an owned `String` for inspection by
+/// other tools, but since `Stopwatch::name` is a `&'static str`, it can't be restored from
+/// deserialized data and comes back as `None`; see [`Stopwatch::set_name`] to reattach it.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StopwatchData {
+    elapsed_duration: Duration,
+    is_running: bool,
+    name: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl<I: Instant> serde::Serialize for Stopwatch<I> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StopwatchData {
+            elapsed_duration: self.elapsed(),
+            is_running: self.is_running(),
+            name: self.name.map(str::to_owned),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Restores a `Stopwatch` from its accumulated elapsed time. If it was running when
+/// serialized, `start()` is called so the clock resumes counting from the current instant.
+/// The name is not restored; see [`StopwatchData`].
+#[cfg(feature = "serde")]
+impl<'de, I: Instant> serde::Deserialize<'de> for Stopwatch<I> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = StopwatchData::deserialize(deserializer)?;
+        let mut stopwatch = Stopwatch {
+            start_time: None,
+            elapsed_duration: data.elapsed_duration,
+            name: None,
+        };
+        if data.is_running {
+            stopwatch.start();
+        }
+        Ok(stopwatch)
+    }
+}
+
+/// Whether a [`Timer`] finishes once or keeps repeating.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimerMode {
+    /// The timer stops advancing once it reaches its duration.
+    Once,
+    /// The timer wraps back to zero once it reaches its duration and keeps counting.
+    Repeating,
+}
+
+/// A countdown/interval timer counting towards a fixed `duration`, advanced by explicit
+/// `tick(delta)` calls rather than by reading a clock.
+#[derive(Clone, Copy, Debug)]
+pub struct Timer {
+    duration: Duration,
+    mode: TimerMode,
+    elapsed: Duration,
+    finished: bool,
+    times_finished_this_tick: u32,
+    paused: bool,
+}
+
+impl Timer {
+    /// Creates a new, unstarted `Timer` for the given `duration` and `mode`.
+    pub fn new(duration: Duration, mode: TimerMode) -> Timer {
+        Timer {
+            duration,
+            mode,
+            elapsed: Duration::ZERO,
+            finished: false,
+            times_finished_this_tick: 0,
+            paused: false,
+        }
+    }
+
+    /// Returns the fixed duration this timer counts towards.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the mode (`Once` or `Repeating`) this timer was created with.
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
+    /// Returns the time elapsed in the current cycle. For a repeating timer this wraps back
+    /// towards zero every time `duration` is reached.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns whether the timer is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pauses the timer. While paused, `tick()` leaves `elapsed()` untouched.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes a paused timer.
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns true if the timer has reached its duration. For a non-repeating timer this
+    /// stays true on every tick after it first finishes; for a repeating timer it behaves
+    /// like [`Timer::just_finished`].
+    pub fn finished(&self) -> bool {
+        match self.mode {
+            TimerMode::Once => self.finished,
+            TimerMode::Repeating => self.just_finished(),
+        }
+    }
+
+    /// Returns true if the timer's duration was reached during the most recent `tick()` call.
+    pub fn just_finished(&self) -> bool {
+        self.times_finished_this_tick > 0
+    }
+
+    /// Returns how many full periods were consumed during the most recent `tick()` call.
+    /// Normally `0` or `1`, but can be greater for a repeating timer if a single `delta`
+    /// spans multiple periods.
+    pub fn times_finished(&self) -> u32 {
+        self.times_finished_this_tick
+    }
+
+    /// Returns the fraction (in `[0.0, 1.0]`) of the current period still remaining.
+    pub fn percent_left(&self) -> f32 {
+        if self.duration.is_zero() {
+            0.0
+        } else {
+            1.0 - self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        }
+    }
+
+    /// Restores the timer to a non-finished, zero-elapsed state. Whether the timer is paused
+    /// is left unchanged.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.finished = false;
+        self.times_finished_this_tick = 0;
+    }
+
+    /// Advances the timer by `delta`. `times_finished()` and `just_finished()` always reflect
+    /// only this call, and a repeating timer wraps `delta` across as many full periods as it
+    /// spans.
+    pub fn tick(&mut self, delta: Duration) {
+        self.times_finished_this_tick = 0;
+
+        if self.paused {
+            return;
+        }
+        if self.mode == TimerMode::Once && self.finished {
+            return;
+        }
+
+        self.elapsed += delta;
+
+        if self.duration.is_zero() {
+            self.finished = true;
+            self.times_finished_this_tick = 1;
+            return;
+        }
+
+        match self.mode {
+            TimerMode::Once => {
+                if self.elapsed >= self.duration {
+                    self.elapsed = self.duration;
+                    self.finished = true;
+                    self.times_finished_this_tick = 1;
+                }
+            }
+            TimerMode::Repeating => {
+                while self.elapsed >= self.duration {
+                    self.elapsed -= self.duration;
+                    self.times_finished_this_tick += 1;
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -301,4 +736,331 @@ mod tests {
         stopwatch.stop();
         assert!(!stopwatch.is_running());
     }
+
+    #[test]
+    fn pause_and_resume_are_aliases_for_stop_and_start() {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.resume();
+        assert!(stopwatch.is_running());
+        thread::sleep(DURATION_TO_USE);
+        stopwatch.pause();
+        assert!(!stopwatch.is_running());
+        thread::sleep(DURATION_TO_USE);
+        stopwatch.resume();
+        thread::sleep(DURATION_TO_USE);
+        stopwatch.pause();
+        assert_eq_with_min(&stopwatch, 2 * DURATION_TO_USE);
+    }
+
+    #[test]
+    fn named_stopwatch_reports_its_name() {
+        let stopwatch = Stopwatch::new_named("request");
+        assert_eq!(stopwatch.name(), Some("request"));
+
+        let mut unnamed = Stopwatch::new();
+        assert_eq!(unnamed.name(), None);
+        unnamed.set_name("request");
+        assert_eq!(unnamed.name(), Some("request"));
+    }
+
+    fn stopwatch_with_elapsed(elapsed: Duration) -> Stopwatch {
+        Stopwatch {
+            start_time: None,
+            elapsed_duration: elapsed,
+            name: None,
+        }
+    }
+
+    #[test]
+    fn display_while_running_has_no_duration() {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.start();
+        assert_eq!(format!("{stopwatch}"), "running");
+
+        let mut named = Stopwatch::new_named("request");
+        named.start();
+        assert_eq!(format!("{named}"), "request running");
+    }
+
+    #[test]
+    fn display_picks_the_most_legible_unit() {
+        let cases: [(Duration, &str); 4] = [
+            (Duration::from_nanos(500), "took 500ns"),
+            (Duration::from_micros(250), "took 250.00us"),
+            (Duration::from_millis(3), "took 3.00ms"),
+            (Duration::from_millis(3_420), "took 3.42s"),
+        ];
+        for (duration, expected) in cases {
+            let stopwatch = stopwatch_with_elapsed(duration);
+            assert_eq!(format!("{stopwatch}"), expected);
+        }
+    }
+
+    #[test]
+    fn display_with_name_prefixes_the_message() {
+        let mut named = stopwatch_with_elapsed(Duration::from_millis(3));
+        named.set_name("request");
+        assert_eq!(format!("{named}"), "request took 3.00ms");
+    }
+
+    #[test]
+    fn guard_stops_on_scope_exit() {
+        let mut stopwatch = Stopwatch::new();
+        {
+            let _guard = stopwatch.guard();
+            thread::sleep(DURATION_TO_USE);
+        }
+        assert!(!stopwatch.is_running());
+        assert_eq_with_min(&stopwatch, DURATION_TO_USE);
+    }
+
+    #[test]
+    fn guard_stops_on_early_return() {
+        fn time_with_early_return(stopwatch: &mut Stopwatch, take_early_path: bool) {
+            let _guard = stopwatch.guard();
+            thread::sleep(DURATION_TO_USE);
+            if take_early_path {
+                return;
+            }
+            thread::sleep(DURATION_TO_USE);
+        }
+
+        let mut stopwatch = Stopwatch::new();
+        time_with_early_return(&mut stopwatch, true);
+        assert!(!stopwatch.is_running());
+        assert_eq_with_min(&stopwatch, DURATION_TO_USE);
+    }
+
+    #[test]
+    fn nested_guards_accumulate_independently() {
+        let mut outer = Stopwatch::new();
+        let mut inner = Stopwatch::new();
+        {
+            let _outer_guard = outer.guard();
+            thread::sleep(DURATION_TO_USE);
+            {
+                let _inner_guard = inner.guard();
+                thread::sleep(DURATION_TO_USE);
+            }
+            assert!(!inner.is_running());
+            thread::sleep(DURATION_TO_USE);
+        }
+        assert!(!outer.is_running());
+        assert_eq_with_min(&inner, DURATION_TO_USE);
+        assert_eq_with_min(&outer, 3 * DURATION_TO_USE);
+    }
+
+    #[test]
+    fn guard_is_noop_if_stopped_manually_before_drop() {
+        let mut stopwatch = Stopwatch::new();
+        {
+            let mut guard = stopwatch.guard();
+            thread::sleep(DURATION_TO_USE);
+            guard.stop();
+            thread::sleep(DURATION_TO_USE);
+        }
+        assert_eq_with_min(&stopwatch, DURATION_TO_USE);
+    }
+
+    #[test]
+    fn owned_guard_stops_shared_stopwatch_on_drop() {
+        let (stopwatch, guard) = Stopwatch::new_guarded();
+        thread::sleep(DURATION_TO_USE);
+        drop(guard);
+        thread::sleep(DURATION_TO_USE);
+        let stopwatch = stopwatch.lock().unwrap();
+        assert!(!stopwatch.is_running());
+        assert_eq_dur_with_min(stopwatch.elapsed(), DURATION_TO_USE);
+    }
+
+    #[test]
+    fn benchmark_runs_closure_iterations_times() {
+        let mut calls = 0;
+        let result = benchmark(5, || calls += 1);
+        assert_eq!(calls, 5);
+        assert_eq!(result.iterations(), 5);
+    }
+
+    #[test]
+    fn benchmark_zero_iterations_does_not_divide_by_zero() {
+        let mut calls = 0;
+        let result = benchmark(0, || calls += 1);
+        assert_eq!(calls, 0);
+        assert_eq!(result.per_iteration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn benchmark_per_iteration_uses_integer_division() {
+        let result = benchmark(3, || thread::sleep(DURATION_TO_USE));
+        assert_eq_dur_with_min(result.total(), 3 * DURATION_TO_USE);
+        assert_eq!(result.per_iteration(), result.total() / 3);
+    }
+
+    #[test]
+    fn benchmark_all_times_each_closure_separately() {
+        let mut fast_calls = 0;
+        let mut slow_calls = 0;
+        let mut fast = || fast_calls += 1;
+        let mut slow = || {
+            slow_calls += 1;
+            thread::sleep(DURATION_TO_USE);
+        };
+        let results = benchmark_all(2, &mut [("fast", &mut fast), ("slow", &mut slow)]);
+        assert_eq!(fast_calls, 2);
+        assert_eq!(slow_calls, 2);
+        assert_eq!(results[0].0, "fast");
+        assert_eq!(results[1].0, "slow");
+        assert!(results[1].1 >= 2 * DURATION_TO_USE);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_preserves_elapsed_when_stopped() {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.start();
+        thread::sleep(DURATION_TO_USE);
+        stopwatch.stop();
+
+        let serialized = serde_json::to_string(&stopwatch).unwrap();
+        let deserialized: Stopwatch = serde_json::from_str(&serialized).unwrap();
+
+        assert!(!deserialized.is_running());
+        assert_eq!(deserialized.elapsed(), stopwatch.elapsed());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_resumes_running_stopwatch() {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.start();
+        thread::sleep(DURATION_TO_USE);
+
+        let serialized = serde_json::to_string(&stopwatch).unwrap();
+        thread::sleep(DURATION_TO_USE);
+        let deserialized: Stopwatch = serde_json::from_str(&serialized).unwrap();
+
+        assert!(deserialized.is_running());
+        assert_eq_dur_with_min(deserialized.elapsed(), DURATION_TO_USE);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_serializes_name_but_does_not_restore_it() {
+        let stopwatch = Stopwatch::new_named("request");
+
+        let serialized = serde_json::to_string(&stopwatch).unwrap();
+        assert!(serialized.contains("request"));
+
+        let deserialized: Stopwatch = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.name(), None);
+    }
+
+    #[test]
+    fn timer_once_finishes_and_stays_finished() {
+        let mut timer = Timer::new(Duration::from_secs(1), TimerMode::Once);
+        assert!(!timer.finished());
+        timer.tick(Duration::from_millis(600));
+        assert!(!timer.finished());
+        assert!(!timer.just_finished());
+        timer.tick(Duration::from_millis(600));
+        assert!(timer.finished());
+        assert!(timer.just_finished());
+        assert_eq!(timer.times_finished(), 1);
+        assert_eq!(timer.elapsed(), Duration::from_secs(1));
+        // Once finished, a non-repeating timer stays finished but stops reporting
+        // just_finished() on later ticks.
+        timer.tick(Duration::from_millis(600));
+        assert!(timer.finished());
+        assert!(!timer.just_finished());
+        assert_eq!(timer.times_finished(), 0);
+    }
+
+    #[test]
+    fn timer_percent_left() {
+        let mut timer = Timer::new(Duration::from_secs(1), TimerMode::Once);
+        assert_eq!(timer.percent_left(), 1.0);
+        timer.tick(Duration::from_millis(250));
+        assert_eq!(timer.percent_left(), 0.75);
+        timer.tick(Duration::from_millis(750));
+        assert_eq!(timer.percent_left(), 0.0);
+    }
+
+    #[test]
+    fn timer_repeating_wraps_and_reports_single_completion() {
+        let mut timer = Timer::new(Duration::from_secs(1), TimerMode::Repeating);
+        timer.tick(Duration::from_millis(700));
+        assert!(!timer.just_finished());
+        timer.tick(Duration::from_millis(700));
+        assert!(timer.just_finished());
+        assert_eq!(timer.times_finished(), 1);
+        assert_eq!(timer.elapsed(), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn timer_repeating_large_delta_counts_all_periods_consumed() {
+        let mut timer = Timer::new(Duration::from_secs(1), TimerMode::Repeating);
+        timer.tick(Duration::from_millis(3_500));
+        assert!(timer.just_finished());
+        assert_eq!(timer.times_finished(), 3);
+        assert_eq!(timer.elapsed(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn timer_two_successive_completing_ticks_both_report_just_finished() {
+        let mut timer = Timer::new(Duration::from_millis(500), TimerMode::Repeating);
+        timer.tick(Duration::from_millis(500));
+        assert!(timer.just_finished());
+        timer.tick(Duration::from_millis(500));
+        assert!(timer.just_finished());
+    }
+
+    #[test]
+    fn timer_paused_tick_resets_times_finished_but_leaves_elapsed_untouched() {
+        let mut timer = Timer::new(Duration::from_secs(1), TimerMode::Repeating);
+        timer.tick(Duration::from_millis(1_200));
+        assert!(timer.just_finished());
+        let elapsed_before_pause = timer.elapsed();
+
+        timer.pause();
+        timer.tick(Duration::from_millis(900));
+        assert!(!timer.just_finished());
+        assert_eq!(timer.times_finished(), 0);
+        assert_eq!(timer.elapsed(), elapsed_before_pause);
+
+        timer.unpause();
+        timer.tick(Duration::ZERO);
+        assert_eq!(timer.elapsed(), elapsed_before_pause);
+    }
+
+    #[test]
+    fn timer_reset_clears_elapsed_and_finished_state() {
+        let mut timer = Timer::new(Duration::from_millis(500), TimerMode::Once);
+        timer.tick(Duration::from_millis(500));
+        assert!(timer.finished());
+        timer.reset();
+        assert!(!timer.finished());
+        assert!(!timer.just_finished());
+        assert_eq!(timer.elapsed(), Duration::ZERO);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct MockInstant(Duration);
+
+    impl Instant for MockInstant {
+        fn now() -> Self {
+            panic!("MockInstant::now() must not be called directly in tests; construct instants explicitly instead");
+        }
+
+        fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+            self.0.checked_sub(earlier.0)
+        }
+    }
+
+    #[test]
+    fn backward_clock_jump_saturates_instead_of_panicking() {
+        let earlier = MockInstant(Duration::from_secs(10));
+        let later = MockInstant(Duration::from_secs(5));
+        assert_eq!(later.checked_duration_since(earlier), None);
+        assert_eq!(later.saturating_duration_since(earlier), Duration::ZERO);
+    }
 }